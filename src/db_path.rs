@@ -0,0 +1,116 @@
+// Not wired into any CLI handler yet; commands will fall back to it when `--file` is omitted.
+#![allow(dead_code)]
+
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Environment variable that overrides the default database path.
+pub const DB_PATH_VAR: &str = "KVRS_DB";
+
+/// Returns the database path used when `--file` is not given.
+///
+/// Checks `KVRS_DB` first, then `kvrs/default.db` under the XDG data directory
+/// (`$XDG_DATA_HOME`, or `$HOME/.local/share` when it is unset, empty or
+/// relative), and falls back to `kvrs.db` in the current directory only when
+/// neither variable gives an absolute path.
+pub fn default_db_path() -> PathBuf {
+    resolve_db_path(
+        env::var_os(DB_PATH_VAR),
+        env::var_os("XDG_DATA_HOME"),
+        env::var_os("HOME"),
+    )
+}
+
+fn resolve_db_path(
+    kvrs_db: Option<OsString>,
+    xdg_data_home: Option<OsString>,
+    home: Option<OsString>,
+) -> PathBuf {
+    if let Some(path) = kvrs_db.filter(|p| !p.is_empty()) {
+        return PathBuf::from(path);
+    }
+
+    let data_home = xdg_data_home
+        .filter(|p| Path::new(p).is_absolute())
+        .map(PathBuf::from)
+        .or_else(|| {
+            home.filter(|p| Path::new(p).is_absolute())
+                .map(|p| PathBuf::from(p).join(".local").join("share"))
+        });
+
+    match data_home {
+        Some(dir) => dir.join("kvrs").join("default.db"),
+        None => PathBuf::from("kvrs.db"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn os(s: &str) -> Option<OsString> {
+        Some(OsString::from(s))
+    }
+
+    // `temp_dir` is absolute on every platform, unlike a literal like `/data`.
+    fn abs(name: &str) -> PathBuf {
+        env::temp_dir().join(name)
+    }
+
+    fn abs_os(name: &str) -> Option<OsString> {
+        Some(abs(name).into_os_string())
+    }
+
+    #[test]
+    fn env_override_wins() {
+        assert_eq!(
+            resolve_db_path(abs_os("my.db"), abs_os("share"), abs_os("home")),
+            abs("my.db")
+        );
+        assert_eq!(
+            resolve_db_path(os("rel.db"), None, None),
+            PathBuf::from("rel.db")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_xdg_data_home() {
+        assert_eq!(
+            resolve_db_path(None, abs_os("share"), abs_os("home")),
+            abs("share").join("kvrs").join("default.db")
+        );
+        assert_eq!(
+            resolve_db_path(os(""), abs_os("data"), None),
+            abs("data").join("kvrs").join("default.db")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_home_local_share() {
+        let expected = abs("home")
+            .join(".local")
+            .join("share")
+            .join("kvrs")
+            .join("default.db");
+        assert_eq!(resolve_db_path(None, None, abs_os("home")), expected);
+        assert_eq!(resolve_db_path(None, os(""), abs_os("home")), expected);
+        assert_eq!(
+            resolve_db_path(None, os("relative/share"), abs_os("home")),
+            expected
+        );
+    }
+
+    #[test]
+    fn falls_back_to_current_directory() {
+        assert_eq!(resolve_db_path(None, None, None), PathBuf::from("kvrs.db"));
+        assert_eq!(
+            resolve_db_path(os(""), os(""), os("")),
+            PathBuf::from("kvrs.db")
+        );
+        assert_eq!(
+            resolve_db_path(None, os("relative/share"), os("relative/home")),
+            PathBuf::from("kvrs.db")
+        );
+    }
+}
@@ -1,3 +1,6 @@
+mod db_path;
+mod size;
+
 use libkvrs::add;
 
 fn main() {
@@ -0,0 +1,146 @@
+// Not wired into any CLI flag yet; size options such as `--alignment` will use it.
+#![allow(dead_code)]
+
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseSizeError {
+    Empty,
+    InvalidNumber(String),
+    UnknownSuffix(String),
+    Overflow,
+}
+
+impl fmt::Display for ParseSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseSizeError::Empty => write!(f, "size is empty"),
+            ParseSizeError::InvalidNumber(s) => write!(
+                f,
+                "invalid size {:?}, expected a whole number of bytes with an optional unit",
+                s
+            ),
+            ParseSizeError::UnknownSuffix(s) => {
+                write!(
+                    f,
+                    "unknown size suffix {:?}, expected B, K/KiB, M/MiB, G/GiB or T/TiB (multiples of 1024)",
+                    s
+                )
+            }
+            ParseSizeError::Overflow => write!(f, "size does not fit into 64 bits"),
+        }
+    }
+}
+
+impl std::error::Error for ParseSizeError {}
+
+/// Parses a human-readable size such as `4096`, `4K`, `1MiB` or `2G` into bytes.
+///
+/// Units are binary multiples (`1K` = `1KiB` = 1024 bytes). The K/M/G/T letter
+/// may be lowercase, but `KB`-style suffixes are rejected because they commonly
+/// mean decimal multiples of 1000.
+pub fn parse_size(s: &str) -> Result<u64, ParseSizeError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseSizeError::Empty);
+    }
+
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (number, suffix) = s.split_at(split);
+    if number.is_empty() {
+        return Err(ParseSizeError::InvalidNumber(s.to_string()));
+    }
+    let suffix = suffix.trim_start();
+    // Anything between the digits and a unit, like the `.5` in `1.5M` or the
+    // `x10` in `0x10`, means the number itself is not a plain integer.
+    if !suffix.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(ParseSizeError::InvalidNumber(s.to_string()));
+    }
+    let number: u64 = number.parse().map_err(|_| ParseSizeError::Overflow)?;
+
+    let shift = match suffix {
+        "" | "B" => 0,
+        "K" | "k" | "KiB" | "kiB" => 10,
+        "M" | "m" | "MiB" | "miB" => 20,
+        "G" | "g" | "GiB" | "giB" => 30,
+        "T" | "t" | "TiB" | "tiB" => 40,
+        _ => return Err(ParseSizeError::UnknownSuffix(suffix.to_string())),
+    };
+
+    number
+        .checked_mul(1 << shift)
+        .ok_or(ParseSizeError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_size_plain_and_suffixed() {
+        assert_eq!(parse_size("4096"), Ok(4096));
+        assert_eq!(parse_size("0"), Ok(0));
+        assert_eq!(parse_size("12B"), Ok(12));
+        assert_eq!(parse_size("4K"), Ok(4096));
+        assert_eq!(parse_size("4k"), Ok(4096));
+        assert_eq!(parse_size("4KiB"), Ok(4096));
+        assert_eq!(parse_size("1M"), Ok(1 << 20));
+        assert_eq!(parse_size("2G"), Ok(2 << 30));
+        assert_eq!(parse_size("3T"), Ok(3 << 40));
+        assert_eq!(parse_size(" 8 M "), Ok(8 << 20));
+    }
+
+    #[test]
+    fn parse_size_rejects_malformed_input() {
+        assert_eq!(parse_size(""), Err(ParseSizeError::Empty));
+        assert_eq!(parse_size("   "), Err(ParseSizeError::Empty));
+        assert_eq!(
+            parse_size("K"),
+            Err(ParseSizeError::InvalidNumber("K".to_string()))
+        );
+        assert_eq!(
+            parse_size("-4K"),
+            Err(ParseSizeError::InvalidNumber("-4K".to_string()))
+        );
+        assert_eq!(
+            parse_size("1.5M"),
+            Err(ParseSizeError::InvalidNumber("1.5M".to_string()))
+        );
+        assert_eq!(
+            parse_size("0x10"),
+            Err(ParseSizeError::InvalidNumber("0x10".to_string()))
+        );
+        assert_eq!(
+            parse_size("4M5"),
+            Err(ParseSizeError::InvalidNumber("4M5".to_string()))
+        );
+        assert_eq!(
+            parse_size("4X"),
+            Err(ParseSizeError::UnknownSuffix("X".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_size_accepts_only_binary_unit_forms() {
+        assert_eq!(parse_size("1kiB"), Ok(1024));
+        assert_eq!(parse_size("1GiB"), Ok(1 << 30));
+        for suffix in ["KB", "Kb", "kb", "MB", "GB", "TB", "KIB", "Kib", "b"] {
+            assert_eq!(
+                parse_size(&format!("1{}", suffix)),
+                Err(ParseSizeError::UnknownSuffix(suffix.to_string())),
+                "suffix {:?}",
+                suffix
+            );
+        }
+    }
+
+    #[test]
+    fn parse_size_detects_overflow() {
+        assert_eq!(
+            parse_size("18446744073709551616"),
+            Err(ParseSizeError::Overflow)
+        );
+        assert_eq!(parse_size("16777216T"), Err(ParseSizeError::Overflow));
+        assert_eq!(parse_size("16777215T"), Ok(16777215 << 40));
+    }
+}